@@ -8,4 +8,10 @@ pub enum WordleError<'a> {
     WrongLength { expected: usize },
     #[error("\"{word}\" is not a valid word")]
     NotAWord { word: &'a str },
+    #[error("hard mode: your guess must use the revealed hint '{missing}'")]
+    ViolatesHardMode { missing: char },
+    #[error("no words of the configured length were available to play with")]
+    EmptyWordList,
+    #[error("failed to read word list: {0}")]
+    Io(#[from] std::io::Error),
 }
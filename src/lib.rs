@@ -0,0 +1,5 @@
+mod error;
+mod wordle;
+
+pub use error::WordleError;
+pub use wordle::{Assistant, Guess, Occurrence, WordList, Wordle, WordleSettings};
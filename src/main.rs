@@ -1,14 +1,14 @@
-mod error;
-mod wordle;
+use wordle::{Assistant, Occurrence, Wordle, WordleError, WordleSettings};
 
-use wordle::{Wordle, WordleSettings};
-
-use crate::error::WordleError;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     #[cfg(target_os = "windows")]
     let _ = ansi_term::enable_ansi_support().unwrap();
 
-    let mut game = Wordle::new(WordleSettings::default());
+    if std::env::args().nth(1).as_deref() == Some("solve") {
+        return solve();
+    }
+
+    let mut game = Wordle::new(WordleSettings::default())?;
 
     println!("{game}");
 
@@ -38,3 +38,47 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// Solver-companion mode: instead of guessing a secret word, the user enters
+/// the color feedback an external Wordle gave for each guess, and we narrow
+/// down the candidate list round by round.
+fn solve() -> Result<(), Box<dyn std::error::Error>> {
+    let mut assistant = Assistant::new(WordleSettings::default());
+
+    loop {
+        println!("guess:");
+        let mut guess = String::new();
+        std::io::stdin().read_line(&mut guess)?;
+        let guess = guess.trim().to_lowercase();
+
+        println!("feedback (c = correct, p = present, anything else = wrong), e.g. wwcpw:");
+        let mut feedback = String::new();
+        std::io::stdin().read_line(&mut feedback)?;
+        let feedback: Vec<Occurrence> = feedback
+            .trim()
+            .chars()
+            .map(|c| match c {
+                'c' => Occurrence::Correct,
+                'p' => Occurrence::Present,
+                _ => Occurrence::Wrong,
+            })
+            .collect();
+
+        if let Err(e) = assistant.narrow(&guess, &feedback) {
+            eprintln!("{e}");
+            continue;
+        }
+
+        let candidates = assistant.candidates();
+        println!("{} candidate(s) remaining:", candidates.len());
+        for word in candidates.iter().take(10) {
+            println!("  {word}");
+        }
+
+        if candidates.len() <= 1 {
+            break;
+        }
+    }
+
+    Ok(())
+}
@@ -1,29 +1,170 @@
+use std::collections::HashMap;
 use std::fmt::Display;
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::path::Path;
 
 use crate::error::WordleError;
 
-#[derive(PartialEq, Debug)]
-enum Occurrence {
+#[derive(PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum Occurrence {
     Wrong,
     Present,
     Correct,
 }
 
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct Guess {
     letter: char,
     occurrence: Occurrence,
 }
 
-#[derive(Clone)]
+/// Scores `guess` against `word`, producing the per-letter `Occurrence`
+/// pattern a player would see: letters marked correct first, then any
+/// leftover letters that are present elsewhere in the word, carefully
+/// consuming each letter of `word` at most once so duplicates are handled
+/// the same way the real game does.
+fn score(word_length: usize, word: &str, guess: &str) -> Vec<Occurrence> {
+    let mut try_word: Vec<Option<char>> = word.chars().map(Some).collect();
+    let mut try_guess: Vec<Option<char>> = guess.chars().map(Some).collect();
+
+    let mut occurrences: Vec<Occurrence> = (0..word_length).map(|_| Occurrence::Wrong).collect();
+
+    // ...then we mark letters as correct...
+    for i in 0..word_length {
+        if try_guess[i] == try_word[i] {
+            occurrences[i] = Occurrence::Correct;
+            // remove letter from possibilities
+            try_guess[i] = None;
+            try_word[i] = None;
+        }
+    }
+    // ...and finally we check if any leftover letters are present,
+    // but in the wrong space.
+    for i in 0..word_length {
+        if let Some(g) = try_guess[i] {
+            if try_word.contains(&Some(g)) {
+                occurrences[i] = Occurrence::Present;
+
+                // Get the actual position of the character to remove it
+                let position = try_word.iter().position(|&f| f == Some(g));
+
+                try_word[position.unwrap()] = None;
+                try_guess[i] = None;
+            };
+        }
+    }
+
+    occurrences
+}
+
+/// Packs a pattern into a base-3 digit key (one of the `3^word_length`
+/// possible patterns) so patterns can be bucketed in a `HashMap`.
+fn pattern_key(pattern: &[Occurrence]) -> usize {
+    pattern.iter().fold(0, |acc, occurrence| {
+        acc * 3
+            + match occurrence {
+                Occurrence::Wrong => 0,
+                Occurrence::Present => 1,
+                Occurrence::Correct => 2,
+            }
+    })
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub enum WordList {
     BuiltIn,
     Custom(Vec<String>),
 }
 
+fn load_word_list(word_list: WordList) -> Vec<String> {
+    match word_list {
+        WordList::BuiltIn => include_str!("../list")
+            .lines()
+            .into_iter()
+            .map(|f| f.to_string())
+            .collect(),
+        WordList::Custom(list) => list,
+    }
+}
+
+impl WordList {
+    /// Loads a dictionary from a file on disk, keeping only lines of
+    /// `word_length` characters and normalizing case.
+    pub fn from_path(
+        path: impl AsRef<Path>,
+        word_length: usize,
+    ) -> Result<WordList, WordleError<'static>> {
+        let file = File::open(path)?;
+        WordList::from_reader(BufReader::new(file), word_length)
+    }
+
+    /// Loads a dictionary from any `BufRead` source, keeping only lines of
+    /// `word_length` characters and normalizing case.
+    pub fn from_reader(
+        reader: impl BufRead,
+        word_length: usize,
+    ) -> Result<WordList, WordleError<'static>> {
+        let words = reader
+            .lines()
+            .collect::<Result<Vec<String>, _>>()?
+            .into_iter()
+            .map(|line| line.trim().to_lowercase())
+            .filter(|line| line.chars().count() == word_length)
+            .collect();
+
+        Ok(WordList::Custom(words))
+    }
+}
+
+/// Plays alongside an external Wordle: instead of holding a secret word, it
+/// keeps a shrinking list of candidates consistent with the color feedback
+/// reported back for each guess.
+pub struct Assistant {
+    word_length: usize,
+    candidates: Vec<String>,
+}
+
+impl Assistant {
+    pub fn new(settings: WordleSettings) -> Assistant {
+        Assistant {
+            word_length: settings.word_length,
+            candidates: load_word_list(settings.word_list),
+        }
+    }
+
+    /// Narrows the candidate list down to words that would have produced
+    /// `feedback` if `guess` had been played against them, using the same
+    /// duplicate-aware scoring as `Wordle::guess_word`.
+    pub fn narrow(
+        &mut self,
+        guess: &str,
+        feedback: &[Occurrence],
+    ) -> Result<(), WordleError<'static>> {
+        if guess.chars().count() != self.word_length || feedback.len() != self.word_length {
+            return Err(WordleError::WrongLength {
+                expected: self.word_length,
+            });
+        }
+
+        self.candidates
+            .retain(|candidate| score(self.word_length, candidate, guess) == *feedback);
+
+        Ok(())
+    }
+
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct WordleSettings {
     pub word_length: usize,
     pub max_guesses: usize,
     pub word_list: WordList,
+    pub hard_mode: bool,
 }
 impl WordleSettings {
     pub fn default() -> Self {
@@ -31,6 +172,7 @@ impl WordleSettings {
             word_length: 5,
             max_guesses: 5,
             word_list: WordList::BuiltIn,
+            hard_mode: false,
         }
     }
 }
@@ -43,6 +185,62 @@ pub struct Wordle {
     solved: bool,
 }
 
+/// Wire format for a `Wordle` session: everything but the fully-loaded
+/// `word_list`, which is reconstructed from `settings` on restore instead of
+/// being saved (and, for `WordList::BuiltIn`, duplicated) on every session.
+#[derive(serde::Deserialize)]
+struct WordleState {
+    guesses: Vec<Vec<Guess>>,
+    word: String,
+    settings: WordleSettings,
+    solved: bool,
+}
+
+impl serde::Serialize for Wordle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::Serialize;
+
+        #[derive(serde::Serialize)]
+        struct WordleStateRef<'a> {
+            guesses: &'a Vec<Vec<Guess>>,
+            word: &'a str,
+            settings: &'a WordleSettings,
+            solved: bool,
+        }
+
+        WordleStateRef {
+            guesses: &self.guesses,
+            word: &self.word,
+            settings: &self.settings,
+            solved: self.solved,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Wordle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+
+        let state = WordleState::deserialize(deserializer)?;
+        let word_list = load_word_list(state.settings.word_list.clone());
+
+        Ok(Wordle {
+            guesses: state.guesses,
+            word: state.word,
+            word_list,
+            settings: state.settings,
+            solved: state.solved,
+        })
+    }
+}
+
 impl Display for Wordle {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         for x in 0..self.settings.max_guesses {
@@ -57,30 +255,26 @@ impl Display for Wordle {
 }
 
 impl Wordle {
-    pub fn new(settings: WordleSettings) -> Wordle {
+    pub fn new(settings: WordleSettings) -> Result<Wordle, WordleError<'static>> {
         use rand::prelude::SliceRandom;
 
-        let word_list: Vec<String> = match settings.word_list.clone() {
-            WordList::BuiltIn => include_str!("../list")
-                .lines()
-                .into_iter()
-                .map(|f| f.to_string())
-                .collect(),
-            WordList::Custom(list) => list,
-        };
+        let word_list = load_word_list(settings.word_list.clone());
 
         let mut rng = rand::thread_rng();
-        let word = word_list.choose(&mut rng).unwrap().to_string();
+        let word = word_list
+            .choose(&mut rng)
+            .ok_or(WordleError::EmptyWordList)?
+            .to_string();
 
         let guesses = vec![];
 
-        Wordle {
+        Ok(Wordle {
             word,
             guesses,
             settings,
             word_list,
             solved: false,
-        }
+        })
     }
     pub fn guess_word(&mut self, guess: String) {
         let guess = guess.trim().to_lowercase();
@@ -100,54 +294,136 @@ impl Wordle {
             return;
         }
 
-        let mut try_word: Vec<Option<char>> = self.word.chars().map(Some).collect();
-        let mut try_guess: Vec<Option<char>> = guess.chars().map(Some).collect();
+        if self.settings.hard_mode {
+            if let Some(missing) = self.hard_mode_violation(&guess) {
+                eprintln!("{}", WordleError::ViolatesHardMode { missing });
+                return;
+            }
+        }
 
-        let mut completed_guess: Vec<Guess> = Vec::with_capacity(self.settings.word_length);
+        let occurrences = score(self.settings.word_length, &self.word, &guess);
+        let completed_guess: Vec<Guess> = guess
+            .chars()
+            .zip(occurrences)
+            .map(|(letter, occurrence)| Guess { letter, occurrence })
+            .collect();
 
-        // Initially, we mark every character as wrong...
-        (0..self.settings.word_length).for_each(|i| {
-            completed_guess.push(Guess {
-                letter: try_guess[i].unwrap(),
-                occurrence: Occurrence::Wrong,
-            });
-        });
+        // Are ya winning, son?
+        self.check_win(&guess);
 
-        // ...then we mark letters as correct...
-        for i in 0..self.settings.word_length {
-            if try_guess[i] == try_word[i] {
-                completed_guess[i] = Guess {
-                    letter: try_word[i].unwrap(),
-                    occurrence: Occurrence::Correct,
-                };
-                // remove letter from possibilities
-                try_guess[i] = None;
-                try_word[i] = None;
+        self.guesses.push(completed_guess);
+    }
+
+    /// Ranks every word in the word list by how much information (in bits of
+    /// Shannon entropy) it's expected to reveal about the answer, given the
+    /// feedback accumulated so far. Highest-information guess comes first.
+    pub fn suggest(&self) -> Vec<(String, f64)> {
+        let possible: Vec<&String> = self
+            .word_list
+            .iter()
+            .filter(|word| self.consistent_with_guesses(word))
+            .collect();
+
+        let total = possible.len() as f64;
+
+        let mut ranked: Vec<(String, f64)> = self
+            .word_list
+            .iter()
+            .map(|guess| {
+                let mut buckets: HashMap<usize, usize> = HashMap::new();
+
+                for answer in &possible {
+                    let pattern = score(self.settings.word_length, answer, guess);
+                    *buckets.entry(pattern_key(&pattern)).or_insert(0) += 1;
+                }
+
+                let entropy = buckets
+                    .values()
+                    .map(|&count| {
+                        let p = count as f64 / total;
+                        -p * p.log2()
+                    })
+                    .sum();
+
+                (guess.clone(), entropy)
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        ranked
+    }
+
+    /// Checks whether `word` could still be the answer given every `Guess`
+    /// recorded so far, accounting for duplicate letters the same way
+    /// `guess_word` does: a `Wrong` alongside a `Correct`/`Present` of the
+    /// same letter means "no additional copies", not "zero copies".
+    fn consistent_with_guesses(&self, word: &str) -> bool {
+        self.guesses.iter().all(|guess| {
+            let guess_word: String = guess.iter().map(|g| g.letter).collect();
+            let pattern = score(self.settings.word_length, word, &guess_word);
+
+            pattern
+                .iter()
+                .zip(guess.iter())
+                .all(|(p, g)| *p == g.occurrence)
+        })
+    }
+
+    /// Hard mode requires every previously revealed hint to be reused: a
+    /// letter marked `Correct` must stay at its known position, and a letter
+    /// marked `Present` must appear somewhere in the guess. Returns the first
+    /// hint letter the guess fails to reuse, if any.
+    fn hard_mode_violation(&self, guess: &str) -> Option<char> {
+        let guess_chars: Vec<char> = guess.chars().collect();
+
+        let mut required_at: HashMap<usize, char> = HashMap::new();
+        let mut required_count: HashMap<char, usize> = HashMap::new();
+
+        for past in &self.guesses {
+            let mut seen_count: HashMap<char, usize> = HashMap::new();
+
+            for (i, g) in past.iter().enumerate() {
+                match g.occurrence {
+                    Occurrence::Correct => {
+                        required_at.insert(i, g.letter);
+                        *seen_count.entry(g.letter).or_insert(0) += 1;
+                    }
+                    Occurrence::Present => {
+                        *seen_count.entry(g.letter).or_insert(0) += 1;
+                    }
+                    Occurrence::Wrong => {}
+                }
+            }
+
+            for (letter, count) in seen_count {
+                let required = required_count.entry(letter).or_insert(0);
+                *required = (*required).max(count);
             }
         }
-        // ...and finally we check if any leftover letters are present,
-        // but in the wrong space.
+
+        // Walk positions and letters in a fixed order so the reported
+        // `missing` hint is deterministic rather than depending on HashMap
+        // iteration order.
         for i in 0..self.settings.word_length {
-            if let Some(g) = try_guess[i] {
-                if try_word.contains(&Some(g)) {
-                    completed_guess[i] = Guess {
-                        letter: g,
-                        occurrence: Occurrence::Present,
-                    };
-
-                    // Get the actual position of the character to remove it
-                    let position = try_word.iter().position(|&f| f == Some(g));
-
-                    try_word[position.unwrap()] = None;
-                    try_guess[i] = None;
-                };
+            if let Some(&letter) = required_at.get(&i) {
+                if guess_chars.get(i) != Some(&letter) {
+                    return Some(letter);
+                }
             }
         }
 
-        // Are ya winning, son?
-        self.check_win(&guess);
+        let mut required_letters: Vec<char> = required_count.keys().copied().collect();
+        required_letters.sort_unstable();
 
-        self.guesses.push(completed_guess);
+        for letter in required_letters {
+            let count = required_count[&letter];
+            let actual = guess_chars.iter().filter(|&&c| c == letter).count();
+            if actual < count {
+                return Some(letter);
+            }
+        }
+
+        None
     }
 
     pub(super) fn get_cell(&self, x: usize, y: usize) -> Option<&Guess> {
@@ -164,6 +440,25 @@ impl Wordle {
         }
     }
 
+    /// Renders the guess history as the familiar spoiler-free emoji grid
+    /// (⬛/🟨/🟩), one line per guess, ready to be shared.
+    pub fn share_grid(&self) -> String {
+        self.guesses
+            .iter()
+            .map(|guess| {
+                guess
+                    .iter()
+                    .map(|g| match g.occurrence {
+                        Occurrence::Wrong => '⬛',
+                        Occurrence::Present => '🟨',
+                        Occurrence::Correct => '🟩',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Prints a guess in the correct color
     fn print(&self, guess: Option<&Guess>) -> String {
         use ansi_term::Colour;
@@ -240,7 +535,9 @@ mod tests {
                 word_length: 5,
                 max_guesses: 1,
                 word_list: WordList::Custom(vec![line.word]),
-            });
+                hard_mode: false,
+            })
+            .unwrap();
 
             game.guess_word(line.guess);
 
@@ -257,4 +554,161 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn word_list_from_reader_filters_length_and_normalizes_case() {
+        let data = "Apple\nbee\nPEAR\ncat\n";
+        let reader = BufReader::new(data.as_bytes());
+
+        let word_list = WordList::from_reader(reader, 4).unwrap();
+
+        match word_list {
+            WordList::Custom(words) => assert_eq!(words, vec!["pear".to_string()]),
+            WordList::BuiltIn => panic!("expected a custom word list"),
+        }
+    }
+
+    #[test]
+    fn suggest_ranks_every_word_by_entropy() {
+        // With exactly two equally-likely candidates left, any guess drawn
+        // from the word list either tells them apart (1 bit of entropy) or
+        // it doesn't - here both candidates do, so every ranked entry should
+        // land on exactly 1 bit.
+        let game = Wordle::new(WordleSettings {
+            word_length: 1,
+            max_guesses: 1,
+            word_list: WordList::Custom(vec!["a".to_string(), "b".to_string()]),
+            hard_mode: false,
+        })
+        .unwrap();
+
+        let ranked = game.suggest();
+
+        assert_eq!(ranked.len(), 2);
+        for (_, entropy) in ranked {
+            assert!((entropy - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn consistent_with_guesses_applies_duplicate_aware_constraints() {
+        // A past guess of "ab" that came back Correct/Wrong means the
+        // secret's first letter is 'a' and it contains no 'b' at all.
+        let game = Wordle {
+            guesses: vec![vec![
+                Guess {
+                    letter: 'a',
+                    occurrence: Occurrence::Correct,
+                },
+                Guess {
+                    letter: 'b',
+                    occurrence: Occurrence::Wrong,
+                },
+            ]],
+            word: "ac".to_string(),
+            word_list: vec!["ac".to_string(), "ab".to_string(), "ba".to_string()],
+            settings: WordleSettings {
+                word_length: 2,
+                max_guesses: 5,
+                word_list: WordList::Custom(vec![]),
+                hard_mode: false,
+            },
+            solved: false,
+        };
+
+        assert!(game.consistent_with_guesses("ac"));
+        assert!(!game.consistent_with_guesses("ab"), "still contains 'b'");
+        assert!(
+            !game.consistent_with_guesses("ba"),
+            "first letter must stay 'a'"
+        );
+    }
+
+    #[test]
+    fn narrow_validates_length_and_filters_candidates() {
+        let mut assistant = Assistant::new(WordleSettings {
+            word_length: 2,
+            max_guesses: 1,
+            word_list: WordList::Custom(vec![
+                "ac".to_string(),
+                "ab".to_string(),
+                "ba".to_string(),
+            ]),
+            hard_mode: false,
+        });
+
+        assert!(assistant.narrow("a", &[Occurrence::Correct]).is_err());
+        assert_eq!(assistant.candidates().len(), 3);
+
+        assistant
+            .narrow("ab", &[Occurrence::Correct, Occurrence::Wrong])
+            .unwrap();
+
+        assert_eq!(assistant.candidates(), ["ac".to_string()]);
+    }
+
+    #[test]
+    fn share_grid_renders_the_emoji_row_for_each_guess() {
+        let mut game = Wordle::new(WordleSettings {
+            word_length: 2,
+            max_guesses: 2,
+            word_list: WordList::Custom(vec!["ac".to_string()]),
+            hard_mode: false,
+        })
+        .unwrap();
+
+        game.guess_word("bc".to_string());
+
+        assert_eq!(game.share_grid(), "⬛🟩");
+    }
+
+    #[test]
+    fn wordle_session_round_trips_through_serde_without_the_word_list() {
+        let mut game = Wordle::new(WordleSettings {
+            word_length: 2,
+            max_guesses: 2,
+            word_list: WordList::Custom(vec!["ac".to_string(), "bd".to_string()]),
+            hard_mode: false,
+        })
+        .unwrap();
+
+        game.guess_word("bd".to_string());
+
+        let serialized = serde_json::to_string(&game).unwrap();
+        // `settings.word_list` is saved; only the session's own loaded list is left out.
+        let value: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+        assert!(
+            value.get("word_list").is_none(),
+            "the fully-loaded word list should not be part of the saved session"
+        );
+
+        let restored: Wordle = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(restored.word(), game.word());
+        assert_eq!(restored.guess_amount(), game.guess_amount());
+        assert_eq!(restored.share_grid(), game.share_grid());
+    }
+
+    #[test]
+    fn hard_mode_rejects_a_guess_that_drops_a_revealed_hint() {
+        let mut game = Wordle::new(WordleSettings {
+            word_length: 5,
+            max_guesses: 5,
+            word_list: WordList::Custom(vec!["apple".to_string()]),
+            hard_mode: true,
+        })
+        .unwrap();
+
+        // "apply" against "apple" reveals a/p/p/l as Correct.
+        game.guess_word("apply".to_string());
+        assert_eq!(game.guess_amount(), 1);
+
+        // Drops every revealed hint, so hard mode should reject it outright.
+        game.guess_word("below".to_string());
+        assert_eq!(
+            game.guess_amount(),
+            1,
+            "hard mode should reject a guess that drops a revealed hint"
+        );
+    }
 }